@@ -1,7 +1,7 @@
 use std::env;
 use std::fs;
 use std::fs::{File, OpenOptions};
-use std::io::{self, Read};
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 
 #[derive(Default, Debug)]
@@ -10,6 +10,61 @@ struct Options {
     force: bool,
     verbose: u8,
     input_file: Option<PathBuf>,
+    from: Option<PathBuf>,
+    max_depth: Option<usize>,
+    follow_symlinks: bool,
+    include_hidden: bool,
+    as_tree: bool,
+    style: TreeStyle,
+    fill: u8,
+    interactive: bool,
+    jobs: usize,
+}
+
+/// Which set of box-drawing glyphs to use when printing a tree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TreeStyle {
+    Unicode,
+    Ascii,
+}
+
+impl Default for TreeStyle {
+    fn default() -> Self {
+        TreeStyle::Unicode
+    }
+}
+
+/// The four connector strings that make up a tree drawing: the vertical guide
+/// for an ancestor with more siblings, the blank guide for a last ancestor, and
+/// the tee/tail connectors for non-last and last entries.
+struct TreeArt {
+    bar: &'static str,
+    blank: &'static str,
+    tee: &'static str,
+    tail: &'static str,
+}
+
+const UNICODE_ART: TreeArt = TreeArt {
+    bar: "│   ",
+    blank: "    ",
+    tee: "├── ",
+    tail: "└── ",
+};
+
+const ASCII_ART: TreeArt = TreeArt {
+    bar: "|   ",
+    blank: "    ",
+    tee: "+-- ",
+    tail: "`-- ",
+};
+
+impl TreeStyle {
+    fn art(self) -> &'static TreeArt {
+        match self {
+            TreeStyle::Unicode => &UNICODE_ART,
+            TreeStyle::Ascii => &ASCII_ART,
+        }
+    }
 }
 
 fn eprintln_v(v: u8, level: u8, msg: impl AsRef<str>) {
@@ -39,6 +94,47 @@ fn parse_args() -> Result<Options, String> {
                     .ok_or_else(|| "--file requires a path".to_string())?;
                 opts.input_file = Some(PathBuf::from(p));
             }
+            "--from" => {
+                let p = args
+                    .next()
+                    .ok_or_else(|| "--from requires a directory".to_string())?;
+                opts.from = Some(PathBuf::from(p));
+            }
+            "--max-depth" => {
+                let n = args
+                    .next()
+                    .ok_or_else(|| "--max-depth requires a number".to_string())?;
+                opts.max_depth = Some(
+                    n.parse()
+                        .map_err(|_| format!("invalid --max-depth value: {}", n))?,
+                );
+            }
+            "--fill" => {
+                let b = args
+                    .next()
+                    .ok_or_else(|| "--fill requires a byte value".to_string())?;
+                opts.fill = parse_fill_byte(&b)?;
+            }
+            "-j" | "--jobs" => {
+                let n = args
+                    .next()
+                    .ok_or_else(|| "--jobs requires a number".to_string())?;
+                opts.jobs = n.parse().map_err(|_| format!("invalid --jobs value: {}", n))?;
+            }
+            "--interactive" => opts.interactive = true,
+            "--follow-symlinks" => opts.follow_symlinks = true,
+            "--hidden" => opts.include_hidden = true,
+            "--tree" => opts.as_tree = true,
+            "--style" => {
+                let s = args
+                    .next()
+                    .ok_or_else(|| "--style requires 'ascii' or 'unicode'".to_string())?;
+                opts.style = match s.as_str() {
+                    "ascii" => TreeStyle::Ascii,
+                    "unicode" => TreeStyle::Unicode,
+                    _ => return Err(format!("invalid --style value: {}", s)),
+                };
+            }
             _ => return Err(format!("Unknown option: {}", arg)),
         }
     }
@@ -46,8 +142,24 @@ fn parse_args() -> Result<Options, String> {
     Ok(opts)
 }
 
+/// Parse a `--fill BYTE` value: a decimal byte (`0`..`255`), a `0xNN` hex byte,
+/// or a single character whose first byte is used.
+fn parse_fill_byte(s: &str) -> Result<u8, String> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return u8::from_str_radix(hex, 16).map_err(|_| format!("invalid --fill byte: {}", s));
+    }
+    if let Ok(n) = s.parse::<u8>() {
+        return Ok(n);
+    }
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(_), None) => Ok(s.as_bytes()[0]),
+        _ => Err(format!("invalid --fill byte: {}", s)),
+    }
+}
+
 fn print_help() {
-    println!("Usage: maketree [OPTIONS]\n\nRead a tree-like structure and create directories and files from it.\n\nInput can be: \n- output from `tree` (with or without `-F`)\n- a simple indented list (2 spaces per level) where directory names end with `/`\n\nIf neither `--file` nor stdin is provided, the program waits for stdin.\n\nOptions:\n  -h, --help        Show this help message\n  -d, --dry-run     Print actions without making changes\n  -f, --force       Replace conflicting files/dirs if needed\n  -i, --file FILE   Read input from file\n  -v, -vv, -vvv     Increase verbosity (1..3)\n\nExamples:\n  tree -F myproj | maketree\n  maketree --file structure.tree\n  cat <<'EOF' | maketree\n  app/\n    src/\n      main.rs\n    Cargo.toml\n  EOF");
+    println!("Usage: maketree [OPTIONS]\n\nRead a tree-like structure and create directories and files from it, or\nsnapshot an existing directory into that format with --from.\n\nInput can be: \n- output from `tree` (with or without `-F`)\n- a simple indented list (2 spaces per level) where directory names end with `/`\n\nIf neither `--file` nor stdin is provided, the program waits for stdin.\n\nOptions:\n  -h, --help            Show this help message\n  -d, --dry-run         Print actions without making changes\n  -f, --force           Replace conflicting files/dirs if needed\n  -i, --file FILE       Read input from file\n  -v, -vv, -vvv         Increase verbosity (1..3)\n  --from DIR            Snapshot DIR and print its tree instead of creating\n  --tree                With --from, emit classic `tree -F` output\n  --style ascii|unicode With --tree, choose the connector glyphs\n  --max-depth N         With --from, limit the walk to N levels\n  --follow-symlinks     With --from, descend into symlinked directories\n  --hidden              With --from, include dot entries\n  --fill BYTE           Byte used to fill sized files (default: 0, sparse)\n  --interactive         Review/prune/rename the parsed tree before creating\n  -j, --jobs N          Create leaf files across N worker threads\n\nExamples:\n  tree -F myproj | maketree\n  maketree --file structure.tree\n  maketree --from myproj > tree.txt   # capture structure, recreate it elsewhere\n  cat <<'EOF' | maketree\n  app/\n    src/\n      main.rs\n    Cargo.toml\n  EOF");
 }
 
 #[derive(Debug, Clone)]
@@ -55,6 +167,30 @@ struct Entry {
     depth: usize,
     name: String,
     is_dir: bool, // may be refined by lookahead for tree-without-slash
+    size: Option<u64>,        // target byte size from a `[N]` annotation
+    content_src: Option<PathBuf>, // local source from a `<<path` annotation
+}
+
+/// Split an optional trailing annotation off a file entry name. Supports
+/// `name [N]` (target byte size) and `name <<source` (seed from a local file),
+/// returning the cleaned name alongside whichever annotation was present.
+fn parse_annotation(name: &str) -> (String, Option<u64>, Option<PathBuf>) {
+    if let Some(idx) = name.rfind("<<") {
+        let base = name[..idx].trim_end().to_string();
+        let src = name[idx + 2..].trim().to_string();
+        if !src.is_empty() {
+            return (base, None, Some(PathBuf::from(src)));
+        }
+    }
+    let trimmed = name.trim_end();
+    if trimmed.ends_with(']') {
+        if let Some(open) = trimmed.rfind('[') {
+            if let Ok(n) = trimmed[open + 1..trimmed.len() - 1].trim().parse::<u64>() {
+                return (trimmed[..open].trim_end().to_string(), Some(n), None);
+            }
+        }
+    }
+    (name.to_string(), None, None)
 }
 
 fn is_stats_line(s: &str) -> bool {
@@ -71,25 +207,33 @@ fn is_stats_line(s: &str) -> bool {
 }
 
 fn parse_tree_style_depth(line: &str) -> Option<(usize, String)> {
-    // For classic `tree` output, before the connector (├── / └──) there are groups of 4 chars: "│   " or "    ".
-    if let Some(conn_pos) = line.find("── ") {
-        let prefix = &line[..conn_pos];
-        let mut i = 0usize;
-        let mut depth = 0usize;
-        let bytes = prefix.as_bytes();
-        while i + 4 <= bytes.len() {
-            let chunk = &prefix[i..i + 4];
-            if chunk == "│   " || chunk == "    " {
-                depth += 1;
-                i += 4;
-            } else {
-                break;
-            }
+    // Both the Unicode form (margins "│   "/"    ", connector "── ") and the
+    // ASCII form emitted by `tree -A` (margins "|   "/"    ", connector "-- ")
+    // are accepted. Locate the connector, then count 4-char margin groups
+    // before it; a group is a margin glyph ('│', '|' or ' ') followed by spaces.
+    let (conn_pos, conn_len) = if let Some(p) = line.find("── ") {
+        (p, "── ".len())
+    } else if let Some(p) = line.find("-- ") {
+        (p, "-- ".len())
+    } else {
+        return None;
+    };
+
+    let prefix: Vec<char> = line[..conn_pos].chars().collect();
+    let mut i = 0usize;
+    let mut depth = 0usize;
+    while i + 4 <= prefix.len() {
+        let head = prefix[i];
+        let spaces = prefix[i + 1] == ' ' && prefix[i + 2] == ' ' && prefix[i + 3] == ' ';
+        if (head == '│' || head == '|' || head == ' ') && spaces {
+            depth += 1;
+            i += 4;
+        } else {
+            break;
         }
-        let name = line[conn_pos + 3..].trim().to_string();
-        return Some((depth, name));
     }
-    None
+    let name = line[conn_pos + conn_len..].trim().to_string();
+    Some((depth, name))
 }
 
 fn parse_indent_list_depth(line: &str) -> (usize, String) {
@@ -109,15 +253,29 @@ fn collect_entries(lines: &[String]) -> Vec<Entry> {
             continue;
         }
 
-        if let Some((depth, name)) = parse_tree_style_depth(raw) {
-            let is_dir = name.ends_with('/');
-            entries.push(Entry { depth, name, is_dir });
+        let (depth, name) = if let Some(parsed) = parse_tree_style_depth(raw) {
+            parsed
         } else {
-            let (depth, name) = parse_indent_list_depth(raw);
-            if name.is_empty() { continue; }
-            let is_dir = name.ends_with('/');
-            entries.push(Entry { depth, name, is_dir });
+            let parsed = parse_indent_list_depth(raw);
+            if parsed.1.is_empty() {
+                continue;
+            }
+            parsed
+        };
+        // Strip any size/content annotation before the directory check so the
+        // trailing `/` and the lookahead-depth inference still work.
+        let (name, size, content_src) = parse_annotation(&name);
+        if name.is_empty() {
+            continue;
         }
+        let is_dir = name.ends_with('/');
+        entries.push(Entry {
+            depth,
+            name,
+            is_dir,
+            size,
+            content_src,
+        });
     }
 
     // Second pass: for `tree` outputs without `-F`, directories won't end with '/'.
@@ -197,6 +355,461 @@ fn touch_file(path: &Path, opts: &Options) -> io::Result<()> {
     }
 }
 
+/// A node discovered while snapshotting a directory.
+struct SnapNode {
+    depth: usize,
+    name: String,
+    is_dir: bool,
+}
+
+/// Read the children of `dir`, sorted directories-first then lexicographically,
+/// skipping hidden entries unless requested. Each child is returned with a flag
+/// indicating whether the walk should treat it as a directory.
+fn read_children(dir: &Path, opts: &Options) -> io::Result<Vec<(PathBuf, bool)>> {
+    let mut children: Vec<(PathBuf, bool)> = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !opts.include_hidden && name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let is_dir = if opts.follow_symlinks {
+            path.is_dir()
+        } else {
+            match fs::symlink_metadata(&path) {
+                Ok(m) if m.file_type().is_symlink() => false,
+                Ok(m) => m.is_dir(),
+                Err(_) => false,
+            }
+        };
+        children.push((path, is_dir));
+    }
+    children.sort_by(|a, b| {
+        b.1.cmp(&a.1).then_with(|| a.0.file_name().cmp(&b.0.file_name()))
+    });
+    Ok(children)
+}
+
+/// Walk `root` depth-first with an explicit stack, producing nodes in the order
+/// they should be printed.
+fn walk_snapshot(root: &Path, opts: &Options) -> io::Result<Vec<SnapNode>> {
+    let mut nodes = Vec::new();
+    // Stack of (path, is_dir, depth); children are pushed reversed so the
+    // deterministic sorted order is preserved when popped.
+    let mut stack: Vec<(PathBuf, bool, usize)> = Vec::new();
+    for (path, is_dir) in read_children(root, opts)?.into_iter().rev() {
+        stack.push((path, is_dir, 0));
+    }
+
+    while let Some((path, is_dir, depth)) = stack.pop() {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        nodes.push(SnapNode {
+            depth,
+            name: name.clone(),
+            is_dir,
+        });
+        let within_depth = opts.max_depth.map_or(true, |m| depth + 1 < m);
+        if is_dir && within_depth {
+            for (child, child_is_dir) in read_children(&path, opts)?.into_iter().rev() {
+                stack.push((child, child_is_dir, depth + 1));
+            }
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// Snapshot `dir` into either the 2-space indented list or classic `tree -F`
+/// output, both of which round-trip back through `collect_entries`.
+fn snapshot(dir: &Path, opts: &Options) -> io::Result<String> {
+    let nodes = walk_snapshot(dir, opts)?;
+    let mut out = String::new();
+    if opts.as_tree {
+        let art = opts.style.art();
+        // A node is the last child at its level when the next node that is not
+        // one of its descendants is shallower (or absent). Track that per level
+        // to draw the vertical guides.
+        let mut ancestor_last: Vec<bool> = Vec::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let is_last = nodes
+                .get(i + 1..)
+                .and_then(|rest| rest.iter().find(|n| n.depth <= node.depth))
+                .map_or(true, |n| n.depth < node.depth);
+            ancestor_last.truncate(node.depth);
+            for &last in &ancestor_last {
+                out.push_str(if last { art.blank } else { art.bar });
+            }
+            out.push_str(if is_last { art.tail } else { art.tee });
+            out.push_str(&node.name);
+            if node.is_dir {
+                out.push('/');
+            }
+            out.push('\n');
+            ancestor_last.push(is_last);
+        }
+    } else {
+        for node in &nodes {
+            out.push_str(&"  ".repeat(node.depth));
+            out.push_str(&node.name);
+            if node.is_dir {
+                out.push('/');
+            }
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+/// Scrollable review state over a parsed `Vec<Entry>`: a viewport of `height`
+/// rows starting at `display_start`, a moving `selection`, and a per-entry
+/// inclusion flag toggled by the user before committing.
+struct Review {
+    entries: Vec<Entry>,
+    included: Vec<bool>,
+    selection: usize,
+    display_start: usize,
+    height: usize,
+}
+
+impl Review {
+    fn new(entries: Vec<Entry>, height: usize) -> Self {
+        let included = vec![true; entries.len()];
+        Review {
+            entries,
+            included,
+            selection: 0,
+            display_start: 0,
+            height,
+        }
+    }
+
+    fn select_prev(&mut self) {
+        if self.selection > 0 {
+            self.selection -= 1;
+        }
+        // Keep the cursor in view: if it dropped above the viewport, scroll up.
+        if self.selection < self.display_start {
+            self.display_start = self.selection;
+        }
+    }
+
+    fn select_next(&mut self) {
+        if self.selection + 1 < self.entries.len() {
+            self.selection += 1;
+        }
+        // If the cursor ran past the bottom of the viewport, scroll down.
+        if self.selection >= self.display_start + self.height {
+            self.display_start += 1;
+        }
+    }
+
+    /// The contiguous run of entries immediately following `i` whose depth is
+    /// strictly greater — i.e. the descendants of entry `i`.
+    fn descendant_span(&self, i: usize) -> usize {
+        let depth = self.entries[i].depth;
+        let mut n = 0;
+        while i + 1 + n < self.entries.len() && self.entries[i + 1 + n].depth > depth {
+            n += 1;
+        }
+        n
+    }
+
+    /// Toggle inclusion of the selected entry and its whole subtree.
+    fn toggle(&mut self) {
+        let i = self.selection;
+        let span = self.descendant_span(i);
+        let new = !self.included[i];
+        for flag in &mut self.included[i..=i + span] {
+            *flag = new;
+        }
+    }
+
+    /// Delete the selected entry and all of its descendants.
+    fn delete(&mut self) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let i = self.selection;
+        let span = self.descendant_span(i);
+        self.entries.drain(i..=i + span);
+        self.included.drain(i..=i + span);
+        if self.selection >= self.entries.len() && self.selection > 0 {
+            self.selection = self.entries.len().saturating_sub(1);
+        }
+        if self.display_start > self.selection {
+            self.display_start = self.selection;
+        }
+    }
+
+    /// Rename the selected entry in place, preserving its directory marker.
+    fn rename(&mut self, name: &str) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let was_dir = self.entries[self.selection].name.ends_with('/');
+        let mut name = name.trim().to_string();
+        if was_dir && !name.ends_with('/') {
+            name.push('/');
+        }
+        self.entries[self.selection].name = name;
+    }
+
+    fn render(&self, out: &mut impl Write) -> io::Result<()> {
+        let end = (self.display_start + self.height).min(self.entries.len());
+        for i in self.display_start..end {
+            let ent = &self.entries[i];
+            let cursor = if i == self.selection { '>' } else { ' ' };
+            let mark = if self.included[i] { 'x' } else { ' ' };
+            writeln!(
+                out,
+                "{} [{}] {}{}",
+                cursor,
+                mark,
+                "  ".repeat(ent.depth),
+                ent.name
+            )?;
+        }
+        writeln!(
+            out,
+            "--- {}/{} entries | j/k move, t toggle, x delete, r NAME rename, c commit, q abort",
+            self.selection + 1,
+            self.entries.len()
+        )?;
+        Ok(())
+    }
+
+    /// Entries the user kept, in order, ready for the creation loop.
+    fn into_committed(self) -> Vec<Entry> {
+        self.entries
+            .into_iter()
+            .zip(self.included)
+            .filter(|(_, keep)| *keep)
+            .map(|(e, _)| e)
+            .collect()
+    }
+}
+
+/// Drive the interactive review. Commands are read a line at a time from the
+/// controlling terminal (so the tree itself can still arrive on stdin), and the
+/// viewport is redrawn after each one. Returns the committed entries, or an
+/// empty vector if the user aborts.
+fn interactive_review(entries: Vec<Entry>, _opts: &Options) -> io::Result<Vec<Entry>> {
+    if entries.is_empty() {
+        return Ok(entries);
+    }
+
+    let mut review = Review::new(entries, 20);
+    let tty = File::open("/dev/tty").map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>);
+    let mut reader: Box<dyn BufRead> = match tty {
+        Ok(r) => r,
+        Err(_) => Box::new(BufReader::new(io::stdin())),
+    };
+    let stdout = io::stdout();
+
+    loop {
+        review.render(&mut stdout.lock())?;
+
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break; // EOF: commit what we have
+        }
+        let cmd = line.trim();
+        match cmd.chars().next() {
+            Some('j') => review.select_next(),
+            Some('k') => review.select_prev(),
+            Some('t') => review.toggle(),
+            Some('x') => review.delete(),
+            Some('r') => review.rename(cmd[1..].trim()),
+            Some('c') => break,
+            Some('q') => return Ok(Vec::new()),
+            _ => {}
+        }
+        if review.entries.is_empty() {
+            break;
+        }
+    }
+
+    Ok(review.into_committed())
+}
+
+/// Resolve every entry to its full path using the same depth-stack logic as the
+/// sequential creation loop, pairing each path with its entry for later use.
+fn resolve_paths(entries: &[Entry]) -> Vec<(PathBuf, Entry)> {
+    let mut stack: Vec<String> = Vec::new();
+    let mut out = Vec::with_capacity(entries.len());
+    for ent in entries {
+        if ent.depth >= stack.len() {
+            stack.resize(ent.depth, String::new());
+        } else {
+            stack.truncate(ent.depth);
+        }
+
+        let name = ent.name.trim_end_matches('/');
+        let mut path = PathBuf::from(".");
+        for comp in &stack {
+            if !comp.is_empty() {
+                path.push(comp);
+            }
+        }
+        path.push(name);
+
+        if ent.is_dir {
+            if ent.depth == stack.len() {
+                stack.push(name.to_string());
+            } else if ent.depth < stack.len() {
+                if ent.depth == 0 {
+                    if stack.is_empty() {
+                        stack.push(name.to_string());
+                    } else {
+                        stack[0] = name.to_string();
+                    }
+                } else {
+                    stack[ent.depth] = name.to_string();
+                }
+            }
+        }
+
+        out.push((path, ent.clone()));
+    }
+    out
+}
+
+/// Create the tree across a scoped worker pool. All directories are created up
+/// front (so parent-ordering is guaranteed and `--force` decisions are made on
+/// the main thread), then independent leaf files are dispatched to `jobs`
+/// workers whose per-path failures are collected and reported together.
+fn run_parallel(entries: Vec<Entry>, opts: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let resolved = resolve_paths(&entries);
+
+    // --dry-run must still print in a stable, sorted order.
+    if opts.dry_run {
+        let mut lines: Vec<String> = resolved
+            .iter()
+            .map(|(p, e)| {
+                if e.is_dir {
+                    format!("Would mkdir -p {}", p.display())
+                } else {
+                    format!("Would touch {}", p.display())
+                }
+            })
+            .collect();
+        lines.sort();
+        for l in lines {
+            println!("{}", l);
+        }
+        return Ok(());
+    }
+
+    // 1. Create every directory (and leaf parent) first, sorted so parents
+    //    precede children.
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for (p, e) in &resolved {
+        if e.is_dir {
+            dirs.push(p.clone());
+        } else if let Some(parent) = p.parent() {
+            dirs.push(parent.to_path_buf());
+        }
+    }
+    dirs.sort();
+    dirs.dedup();
+    for d in &dirs {
+        ensure_dir(d, opts)?;
+    }
+
+    // 2. Resolve leaf conflicts on the main thread so workers only create.
+    let mut leaves: Vec<(PathBuf, Entry)> = Vec::new();
+    for (p, e) in resolved.into_iter() {
+        if e.is_dir {
+            continue;
+        }
+        if p.exists() && p.is_dir() {
+            if opts.force {
+                eprintln_v(opts.verbose, 1, format!("[INFO] Removing dir to create file: {}", p.display()));
+                fs::remove_dir_all(&p)?;
+            } else {
+                return Err(format!(
+                    "Directory exists where file expected: {} (use --force)",
+                    p.display()
+                )
+                .into());
+            }
+        }
+        leaves.push((p, e));
+    }
+
+    // 3. Dispatch leaf creation across the worker pool, collecting failures.
+    let jobs = opts.jobs.min(leaves.len()).max(1);
+    let failures: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+    let next = std::sync::atomic::AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for _ in 0..jobs {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                if i >= leaves.len() {
+                    break;
+                }
+                let (path, ent) = &leaves[i];
+                eprintln_v(opts.verbose, 2, format!("[DEBUG] touch: {}", path.display()));
+                if let Err(e) = create_leaf(path, ent, opts) {
+                    failures
+                        .lock()
+                        .unwrap()
+                        .push(format!("{}: {}", path.display(), e));
+                }
+            });
+        }
+    });
+
+    let mut failures = failures.into_inner().unwrap();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        failures.sort();
+        Err(format!("{} path(s) failed:\n{}", failures.len(), failures.join("\n")).into())
+    }
+}
+
+/// Create a leaf file, applying any size or content-source annotation carried
+/// by `ent`. A `[N]` size is allocated with `set_len` (sparse) unless `--fill`
+/// requests a concrete byte; a `<<source` annotation copies the source bytes.
+fn create_leaf(path: &Path, ent: &Entry, opts: &Options) -> io::Result<()> {
+    touch_file(path, opts)?;
+
+    if opts.dry_run {
+        if let Some(src) = &ent.content_src {
+            println!("Would seed {} from {}", path.display(), src.display());
+        } else if let Some(n) = ent.size {
+            println!("Would allocate {} bytes for {}", n, path.display());
+        }
+        return Ok(());
+    }
+
+    if let Some(src) = &ent.content_src {
+        let bytes = fs::read(src)?;
+        fs::write(path, bytes)?;
+    } else if let Some(n) = ent.size {
+        let mut f = OpenOptions::new().write(true).open(path)?;
+        if opts.fill == 0 {
+            f.set_len(n)?;
+        } else {
+            use std::io::Write;
+            let buf = vec![opts.fill; 8192];
+            let mut remaining = n;
+            while remaining > 0 {
+                let take = remaining.min(buf.len() as u64) as usize;
+                f.write_all(&buf[..take])?;
+                remaining -= take as u64;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let opts = parse_args().map_err(|e| {
         eprintln!("{}", e);
@@ -204,6 +817,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         e
     })?;
 
+    // Snapshot mode: walk an existing directory and print its tree instead.
+    if let Some(dir) = opts.from.clone() {
+        print!("{}", snapshot(&dir, &opts)?);
+        return Ok(());
+    }
+
     // Read input
     let mut input = String::new();
     if let Some(file) = opts.input_file.as_ref() {
@@ -216,6 +835,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
     let entries = collect_entries(&lines);
 
+    // Optionally let the user review/prune/rename before anything is written.
+    let entries = if opts.interactive {
+        interactive_review(entries, &opts)?
+    } else {
+        entries
+    };
+
+    // With --jobs, create directories up front then fan leaves out to workers.
+    if opts.jobs > 1 {
+        return run_parallel(entries, &opts);
+    }
+
     // Build using a stack of path components per depth
     let mut stack: Vec<String> = Vec::new();
     for (idx, ent) in entries.iter().enumerate() {
@@ -254,7 +885,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
         } else {
             eprintln_v(opts.verbose, 2, format!("[DEBUG] touch: {}", path.display()));
-            touch_file(&path, &opts)?;
+            create_leaf(&path, ent, &opts)?;
         }
 
         // Optional: if next entry is shallower or same depth, nothing to do; depth is managed above
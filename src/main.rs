@@ -12,21 +12,92 @@
         /// Paste from clipboard instead of copying
         #[arg(short)]
         paste: bool,
+        /// Bulk-rename the directory listing by editing it in $EDITOR
+        #[arg(short = 'e', long = "edit")]
+        edit: bool,
+        /// Embed file contents when copying a directory (text verbatim, binary base64)
+        #[arg(short = 'c', long = "contents")]
+        contents: bool,
+        /// Compress the clipboard payload (auto-enabled above a size threshold)
+        #[arg(short = 'z', long = "compress")]
+        compress: bool,
+        /// Annotate the directory listing with per-entry and aggregated sizes
+        #[arg(long = "sizes")]
+        sizes: bool,
+        /// Only include files matching a glob pattern (repeatable); directories are always listed
+        #[arg(long = "include", value_name = "GLOB")]
+        include: Vec<String>,
+        /// Skip entries matching a glob pattern (repeatable)
+        #[arg(long = "exclude", alias = "ignore", value_name = "GLOB")]
+        exclude: Vec<String>,
+        /// Honor .gitignore files found while walking
+        #[arg(long = "gitignore")]
+        gitignore: bool,
+        /// Print actions without making changes
+        #[arg(short = 'd', long = "dry-run")]
+        dry_run: bool,
+        /// Increase verbosity (repeatable)
+        #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count)]
+        verbose: u8,
         /// Path to file or directory
         path: PathBuf,
     }
 
     fn main() {
         let cli = Cli::parse();
+        let clipboard = default_clipboard();
 
-        if cli.paste {
-            paste_to_path(&cli.path);
+        if cli.edit {
+            edit_path(&cli.path, cli.dry_run, cli.verbose);
+        } else if cli.paste {
+            paste_to_path(clipboard.as_ref(), &cli.path);
         } else {
-            copy_from_path(&cli.path);
+            let filters = Filters {
+                include: cli.include.clone(),
+                exclude: cli.exclude.clone(),
+                gitignore: cli.gitignore,
+            };
+            copy_from_path(
+                clipboard.as_ref(),
+                &cli.path,
+                cli.contents,
+                cli.compress,
+                cli.sizes,
+                &filters,
+            );
+        }
+    }
+
+    /// Glob/gitignore filters applied while walking a directory.
+    #[derive(Default)]
+    struct Filters {
+        include: Vec<String>,
+        exclude: Vec<String>,
+        gitignore: bool,
+    }
+
+    impl Filters {
+        /// Whether `rel` (a path relative to the walk root, directories without
+        /// a trailing slash) should be skipped outright.
+        fn excluded(&self, rel: &str) -> bool {
+            self.exclude.iter().any(|pat| glob_match(pat, rel))
+        }
+
+        /// Whether `rel` satisfies the include set (always true when no
+        /// `--include` patterns were given).
+        fn included(&self, rel: &str) -> bool {
+            self.include.is_empty() || self.include.iter().any(|pat| glob_match(pat, rel))
         }
     }
 
-    fn copy_from_path(path: &Path) {
+    fn copy_from_path(
+        clipboard: &dyn Clipboard,
+        path: &Path,
+        embed_contents: bool,
+        compress: bool,
+        sizes: bool,
+        filters: &Filters,
+    ) {
         let abs = match fs::canonicalize(path) {
             Ok(p) => p,
             Err(e) => {
@@ -41,7 +112,10 @@
                 // Copy contents
                 match fs::read_to_string(&abs) {
                     Ok(contents) => {
-                        copy_text_to_clipboard(&contents);
+                        if let Err(e) = clipboard.set_text(&contents) {
+                            eprintln!("Error copying to clipboard: {}", e);
+                            exit(1);
+                        }
                         println!("✅ Copied contents of {} to clipboard", abs.display());
                     }
                     Err(e) => {
@@ -51,14 +125,33 @@
                 }
             } else {
                 // Copy file reference
-                copy_file_to_clipboard(&abs);
+                if let Err(e) = clipboard.set_file_reference(&abs) {
+                    eprintln!("Error copying file to clipboard: {}", e);
+                    exit(1);
+                }
                 println!("✅ Copied file {} to clipboard", abs.display());
             }
         } else if abs.is_dir() {
             // Copy file list
-            match get_file_list(&abs) {
+            match get_file_list(&abs, sizes, filters) {
                 Ok(list) => {
-                    copy_text_to_clipboard(&list);
+                    let list = if embed_contents {
+                        match append_content_blocks(&abs, &list) {
+                            Ok(l) => l,
+                            Err(e) => {
+                                eprintln!("Error embedding file contents for '{}': {}", abs.display(), e);
+                                exit(1);
+                            }
+                        }
+                    } else {
+                        list
+                    };
+                    // Auto-enable compression once the payload gets large.
+                    let list = pack_payload(&list, compress || list.len() > COMPRESS_THRESHOLD);
+                    if let Err(e) = clipboard.set_text(&list) {
+                        eprintln!("Error copying to clipboard: {}", e);
+                        exit(1);
+                    }
                     println!("✅ Copied directory structure of {} to clipboard", abs.display());
                 }
                 Err(e) => {
@@ -72,17 +165,28 @@
         }
     }
 
-    fn paste_to_path(path: &Path) {
-        let clipboard_contents = match get_clipboard_contents() {
-            Ok(contents) => contents,
+    fn paste_to_path(clipboard: &dyn Clipboard, path: &Path) {
+        let clipboard_contents = match clipboard.get_text() {
+            Ok(contents) => unpack_payload(&contents),
             Err(e) => {
                 eprintln!("Error getting clipboard contents: {}", e);
                 exit(1);
             }
         };
 
+        // A `--sizes` report is a human-readable snapshot, not a tree; print it
+        // rather than recreating junk directories from the annotations.
+        if clipboard_contents.starts_with(SIZES_MARKER) {
+            print!("{}", clipboard_contents);
+            println!("\nℹ️  This is a --sizes report; nothing was created.");
+            return;
+        }
+
+        // Split off any embedded content blocks, leaving the plain tree listing.
+        let (tree_text, contents) = split_content_blocks(&clipboard_contents);
+
         // Try to parse as tree (for backward compatibility)
-        let lines: Vec<String> = clipboard_contents.lines().map(|s| s.to_string()).collect();
+        let lines: Vec<String> = tree_text.lines().map(|s| s.to_string()).collect();
         let entries = collect_entries(&lines);
 
         if !entries.is_empty() && entries.iter().any(|e| e.depth > 0 || e.is_dir) {
@@ -104,7 +208,7 @@
                 eprintln!("Error changing to directory '{}': {}", base_path.display(), e);
                 exit(1);
             }
-            if let Err(e) = run_maketree_with_entries(entries, opts) {
+            if let Err(e) = run_maketree_with_entries(entries, opts, &contents) {
                 eprintln!("Error creating structure: {}", e);
                 exit(1);
             }
@@ -130,7 +234,8 @@
                             exit(1);
                         }
                     }
-                    if let Err(e) = fs::write(&full_path, "") {
+                    let bytes = contents.get(rel_path_str).map(|b| b.as_slice()).unwrap_or(&[]);
+                    if let Err(e) = fs::write(&full_path, bytes) {
                         eprintln!("Error creating file '{}': {}", full_path.display(), e);
                         exit(1);
                     }
@@ -140,6 +245,167 @@
         }
     }
 
+    fn edit_path(path: &Path, dry_run: bool, verbose: u8) {
+        let abs = match fs::canonicalize(path) {
+            Ok(p) => p,
+            Err(e) => {
+                eprintln!("Error: could not resolve path '{}': {}", path.display(), e);
+                exit(1);
+            }
+        };
+        if !abs.is_dir() {
+            eprintln!("Error: '{}' is not a directory", abs.display());
+            exit(1);
+        }
+
+        let listing = match get_file_list(&abs, false, &Filters::default()) {
+            Ok(l) => l,
+            Err(e) => {
+                eprintln!("Error getting file list for '{}': {}", abs.display(), e);
+                exit(1);
+            }
+        };
+        let originals: Vec<String> = listing
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|s| s.to_string())
+            .collect();
+        if originals.is_empty() {
+            println!("Nothing to rename in {}", abs.display());
+            return;
+        }
+
+        // Write the listing to a temp file, edit it, and read it back.
+        let tmp = std::env::temp_dir().join(format!("meta-edit-{}.txt", std::process::id()));
+        if let Err(e) = fs::write(&tmp, originals.join("\n") + "\n") {
+            eprintln!("Error writing temp file '{}': {}", tmp.display(), e);
+            exit(1);
+        }
+        if let Err(e) = launch_editor(&tmp) {
+            let _ = fs::remove_file(&tmp);
+            eprintln!("Error launching editor: {}", e);
+            exit(1);
+        }
+        let edited_raw = match fs::read_to_string(&tmp) {
+            Ok(c) => c,
+            Err(e) => {
+                let _ = fs::remove_file(&tmp);
+                eprintln!("Error reading edited file '{}': {}", tmp.display(), e);
+                exit(1);
+            }
+        };
+        let _ = fs::remove_file(&tmp);
+
+        let edited: Vec<String> = edited_raw
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|s| s.to_string())
+            .collect();
+        if edited.len() != originals.len() {
+            eprintln!("Error: files added or removed during editing");
+            exit(1);
+        }
+
+        // Pair original line i to edited line i; skip unchanged lines.
+        let mut renames: Vec<(String, String)> = Vec::new();
+        for (from, to) in originals.iter().zip(edited.iter()) {
+            if from != to {
+                renames.push((from.clone(), to.clone()));
+            }
+        }
+        if renames.is_empty() {
+            println!("No changes");
+            return;
+        }
+
+        // Reject batches where two edited lines collide on the same target.
+        let mut targets = std::collections::HashSet::new();
+        for (_, to) in &renames {
+            if !targets.insert(to.clone()) {
+                eprintln!("Error: two entries rename to the same target '{}'", to);
+                exit(1);
+            }
+        }
+
+        if let Err(e) = apply_renames(&abs, &renames, dry_run, verbose) {
+            eprintln!("Error applying renames: {}", e);
+            exit(1);
+        }
+        println!("✅ Applied {} rename(s) in {}", renames.len(), abs.display());
+    }
+
+    fn launch_editor(file: &Path) -> io::Result<()> {
+        let editor = std::env::var("EDITOR")
+            .or_else(|_| std::env::var("VISUAL"))
+            .unwrap_or_else(|_| "vi".to_string());
+        let status = Command::new(&editor).arg(file).status()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("editor '{}' exited with failure", editor),
+            ))
+        }
+    }
+
+    /// Apply `(from, to)` renames relative to `base`, handling cycles and
+    /// overwrites (e.g. `a→b`, `b→a`): first move every source whose target
+    /// already exists or is itself a source to a unique temporary name (other
+    /// sources are renamed directly), then move the temporaries to their finals
+    /// so no rename clobbers a file that is itself a source.
+    fn apply_renames(
+        base: &Path,
+        renames: &[(String, String)],
+        dry_run: bool,
+        verbose: u8,
+    ) -> io::Result<()> {
+        let strip = |s: &str| s.trim_end_matches('/').to_string();
+
+        if dry_run {
+            for (from, to) in renames {
+                println!("Would rename {} -> {}", from, to);
+            }
+            return Ok(());
+        }
+
+        let sources: std::collections::HashSet<String> =
+            renames.iter().map(|(f, _)| strip(f)).collect();
+
+        // First pass: any source whose final target already exists (including
+        // targets that are themselves sources) is moved aside to a temp name.
+        let mut staged: Vec<(PathBuf, String)> = Vec::new();
+        for (i, (from, to)) in renames.iter().enumerate() {
+            let from_rel = strip(from);
+            let to_rel = strip(to);
+            let from_path = base.join(&from_rel);
+            if base.join(&to_rel).exists() || sources.contains(&to_rel) {
+                let tmp = base.join(format!(".meta-rename-{}-{}", std::process::id(), i));
+                eprintln_v(verbose, 1, format!("[INFO] stage {} -> {}", from_path.display(), tmp.display()));
+                fs::rename(&from_path, &tmp)?;
+                staged.push((tmp, to_rel));
+            } else {
+                eprintln_v(verbose, 1, format!("[INFO] rename {} -> {}", from_rel, to_rel));
+                if let Some(parent) = base.join(&to_rel).parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::rename(&from_path, base.join(&to_rel))?;
+            }
+        }
+
+        // Second pass: move staged temporaries to their finals.
+        for (tmp, to_rel) in staged {
+            let to_path = base.join(&to_rel);
+            eprintln_v(verbose, 1, format!("[INFO] finalize {} -> {}", tmp.display(), to_path.display()));
+            if let Some(parent) = to_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&tmp, &to_path)?;
+        }
+
+        Ok(())
+    }
+
     fn is_text_file(path: &Path) -> bool {
         // Simple check: try to read as UTF-8
         if let Ok(contents) = fs::read(path) {
@@ -149,89 +415,731 @@
         }
     }
 
-    fn copy_text_to_clipboard(text: &str) {
-        let mut child = Command::new("pbcopy")
-            .stdin(std::process::Stdio::piped())
-            .spawn()
-            .expect("Failed to run pbcopy");
+    /// Platform-independent clipboard access.
+    ///
+    /// Concrete backends shell out to whatever the host provides (pbcopy on
+    /// macOS, wl-copy/xclip on X11/Wayland, clip/PowerShell on Windows); the
+    /// rest of the tool depends only on this trait so new platforms can be
+    /// added without touching `copy_from_path`/`paste_to_path`.
+    trait Clipboard {
+        /// Place UTF-8 text on the clipboard.
+        fn set_text(&self, text: &str) -> Result<(), Box<dyn std::error::Error>>;
+        /// Place a reference to a file on the clipboard (for pasting into a
+        /// file manager). Backends without a native notion of file references
+        /// fall back to copying the path as text.
+        fn set_file_reference(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>>;
+        /// Read the clipboard as UTF-8 text.
+        fn get_text(&self) -> Result<String, Box<dyn std::error::Error>>;
+    }
+
+    /// Select the clipboard backend for the current platform.
+    fn default_clipboard() -> Box<dyn Clipboard> {
+        #[cfg(target_os = "macos")]
+        {
+            Box::new(MacClipboard)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Box::new(WindowsClipboard)
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+        {
+            Box::new(UnixClipboard)
+        }
+    }
 
+    /// Feed `text` to an external command over stdin.
+    fn pipe_to_command(mut cmd: Command, text: &str) -> Result<(), Box<dyn std::error::Error>> {
         use std::io::Write;
-        child.stdin.as_mut().unwrap().write_all(text.as_bytes()).unwrap();
-        child.wait().unwrap();
+        let mut child = cmd.stdin(std::process::Stdio::piped()).spawn()?;
+        child
+            .stdin
+            .as_mut()
+            .ok_or("failed to open clipboard command stdin")?
+            .write_all(text.as_bytes())?;
+        if child.wait()?.success() {
+            Ok(())
+        } else {
+            Err("clipboard command failed".into())
+        }
     }
 
-    fn copy_file_to_clipboard(path: &Path) {
-        let script = format!(
-            r#"
+    #[cfg(target_os = "macos")]
+    struct MacClipboard;
+
+    #[cfg(target_os = "macos")]
+    impl Clipboard for MacClipboard {
+        fn set_text(&self, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+            pipe_to_command(Command::new("pbcopy"), text)
+        }
+
+        fn set_file_reference(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+            let script = format!(
+                r#"
     tell application "System Events"
         set the clipboard to (POSIX file "{}")
     end tell
     "#,
-            path.display()
-        );
-
-        let status = Command::new("osascript")
-            .arg("-e")
-            .arg(script)
-            .status()
-            .expect("Failed to run osascript");
+                path.display()
+            );
+            let status = Command::new("osascript").arg("-e").arg(script).status()?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err("osascript failed".into())
+            }
+        }
 
-        if !status.success() {
-            eprintln!("Failed to copy file to clipboard");
-            exit(1);
+        fn get_text(&self) -> Result<String, Box<dyn std::error::Error>> {
+            let output = Command::new("pbpaste").output()?;
+            if output.status.success() {
+                Ok(String::from_utf8(output.stdout)?)
+            } else {
+                Err("pbpaste failed".into())
+            }
         }
     }
 
-    fn get_file_list(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
-        // Get dirs
-        let dir_output = Command::new("find")
-            .arg(path)
-            .arg("-type")
-            .arg("d")
-            .output()?;
-        let file_output = Command::new("find")
-            .arg(path)
-            .arg("-type")
-            .arg("f")
-            .output()?;
+    /// X11/Wayland backend: prefer the Wayland tools and fall back to xclip.
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    struct UnixClipboard;
 
-        if !dir_output.status.success() || !file_output.status.success() {
-            return Err("find command failed".into());
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    impl Clipboard for UnixClipboard {
+        fn set_text(&self, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+            pipe_to_command(Command::new("wl-copy"), text).or_else(|_| {
+                let mut cmd = Command::new("xclip");
+                cmd.arg("-selection").arg("clipboard");
+                pipe_to_command(cmd, text)
+            })
         }
 
-        let mut paths = Vec::new();
-        let prefix = path.to_string_lossy().to_string() + "/";
+        fn set_file_reference(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+            // No portable file-reference clipboard on X11/Wayland; copy the path.
+            self.set_text(&path.display().to_string())
+        }
 
-        for line in String::from_utf8(dir_output.stdout)?.lines() {
-            if let Some(rel) = line.strip_prefix(&prefix) {
-                paths.push(rel.to_string() + "/");
-            } else if line == prefix.trim_end_matches('/') {
-                paths.push(".".to_string() + "/");
+        fn get_text(&self) -> Result<String, Box<dyn std::error::Error>> {
+            let run = |cmd: &mut Command| -> Result<String, Box<dyn std::error::Error>> {
+                let output = cmd.output()?;
+                if output.status.success() {
+                    Ok(String::from_utf8(output.stdout)?)
+                } else {
+                    Err("clipboard command failed".into())
+                }
+            };
+            run(&mut Command::new("wl-paste")).or_else(|_| {
+                run(Command::new("xclip").arg("-selection").arg("clipboard").arg("-o"))
+            })
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    struct WindowsClipboard;
+
+    #[cfg(target_os = "windows")]
+    impl Clipboard for WindowsClipboard {
+        fn set_text(&self, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+            pipe_to_command(Command::new("clip"), text)
+        }
+
+        fn set_file_reference(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+            let script = format!("Set-Clipboard -Path '{}'", path.display());
+            let status = Command::new("powershell")
+                .arg("-NoProfile")
+                .arg("-Command")
+                .arg(script)
+                .status()?;
+            if status.success() {
+                Ok(())
+            } else {
+                Err("powershell Set-Clipboard failed".into())
             }
         }
 
-        for line in String::from_utf8(file_output.stdout)?.lines() {
-            if let Some(rel) = line.strip_prefix(&prefix) {
-                paths.push(rel.to_string());
+        fn get_text(&self) -> Result<String, Box<dyn std::error::Error>> {
+            let output = Command::new("powershell")
+                .arg("-NoProfile")
+                .arg("-Command")
+                .arg("Get-Clipboard")
+                .output()?;
+            if output.status.success() {
+                Ok(String::from_utf8(output.stdout)?)
+            } else {
+                Err("powershell Get-Clipboard failed".into())
             }
         }
+    }
+
+    fn get_file_list(
+        path: &Path,
+        sizes: bool,
+        filters: &Filters,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        // Walk the tree in-process (depth-first) rather than shelling out to
+        // `find`, so the tool is portable and can honor include/exclude/gitignore
+        // filters.
+        let mut paths = vec!["./".to_string()];
+        walk_dir(path, path, filters, &[], &mut paths)?;
 
         paths.sort();
+
+        if sizes {
+            return Ok(annotate_sizes(path, &paths));
+        }
         Ok(paths.join("\n"))
     }
 
-    fn get_clipboard_contents() -> Result<String, Box<dyn std::error::Error>> {
-        let output = Command::new("pbpaste").output()?;
-        if output.status.success() {
-            Ok(String::from_utf8(output.stdout)?)
+    /// Depth-first helper for `get_file_list`: append every entry beneath `dir`
+    /// as a path relative to `base` (directories keep a trailing `/`), applying
+    /// the include/exclude globs and any inherited `.gitignore` patterns.
+    fn walk_dir(
+        base: &Path,
+        dir: &Path,
+        filters: &Filters,
+        inherited_ignore: &[String],
+        out: &mut Vec<String>,
+    ) -> io::Result<()> {
+        // In gitignore mode, extend the inherited patterns with this directory's
+        // own `.gitignore`, expressed relative to `base`.
+        let mut ignore_pats = inherited_ignore.to_vec();
+        if filters.gitignore {
+            let dir_rel = dir.strip_prefix(base).map(|r| r.to_string_lossy().to_string()).unwrap_or_default();
+            ignore_pats.extend(read_gitignore(dir, &dir_rel));
+        }
+        let gitignored = |rel: &str| ignore_pats.iter().any(|pat| glob_match(pat, rel));
+
+        let mut children: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .collect();
+        children.sort();
+
+        for child in children {
+            let rel = match child.strip_prefix(base) {
+                Ok(r) => r.to_string_lossy().to_string(),
+                Err(_) => continue,
+            };
+            if filters.excluded(&rel) || gitignored(&rel) {
+                continue;
+            }
+            if child.is_dir() {
+                out.push(format!("{}/", rel));
+                walk_dir(base, &child, filters, &ignore_pats, out)?;
+            } else if filters.included(&rel) {
+                out.push(rel);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse the `.gitignore` in `dir` (if any) into `base`-relative globs.
+    fn read_gitignore(dir: &Path, dir_rel: &str) -> Vec<String> {
+        let mut globs = Vec::new();
+        let contents = match fs::read_to_string(dir.join(".gitignore")) {
+            Ok(c) => c,
+            Err(_) => return globs,
+        };
+        for raw in contents.lines() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                continue;
+            }
+            let dir_only = line.ends_with('/');
+            let pat = line.trim_matches('/');
+            if pat.is_empty() {
+                continue;
+            }
+            let prefix = if dir_rel.is_empty() {
+                String::new()
+            } else {
+                format!("{}/", dir_rel)
+            };
+            if pat.contains('/') {
+                // Anchored to this directory.
+                globs.push(format!("{}{}", prefix, pat));
+                globs.push(format!("{}{}/**", prefix, pat));
+            } else {
+                // Matches at any depth beneath this directory.
+                globs.push(format!("{}{}", prefix, pat));
+                globs.push(format!("{}**/{}", prefix, pat));
+                if dir_only {
+                    globs.push(format!("{}**/{}/**", prefix, pat));
+                }
+                globs.push(format!("{}{}/**", prefix, pat));
+            }
+        }
+        globs
+    }
+
+    /// Minimal glob matcher supporting `?`, single-segment `*`, and the
+    /// recursive `**` wildcard (which crosses path separators).
+    fn glob_match(pattern: &str, text: &str) -> bool {
+        let p: Vec<char> = pattern.chars().collect();
+        let t: Vec<char> = text.chars().collect();
+
+        fn rec(p: &[char], t: &[char]) -> bool {
+            match p.first() {
+                None => t.is_empty(),
+                Some('*') if p.get(1) == Some(&'*') => {
+                    // `**` matches any run of characters, separators included.
+                    let rest = &p[2..];
+                    // `**/` may also match zero directories.
+                    if rest.first() == Some(&'/') && rec(&rest[1..], t) {
+                        return true;
+                    }
+                    if rec(rest, t) {
+                        return true;
+                    }
+                    !t.is_empty() && rec(p, &t[1..])
+                }
+                Some('*') => {
+                    // Match zero or more characters, but not across separators.
+                    if rec(&p[1..], t) {
+                        return true;
+                    }
+                    !t.is_empty() && t[0] != '/' && rec(p, &t[1..])
+                }
+                Some('?') => !t.is_empty() && t[0] != '/' && rec(&p[1..], &t[1..]),
+                Some(&c) => !t.is_empty() && t[0] == c && rec(&p[1..], &t[1..]),
+            }
+        }
+
+        rec(&p, &t)
+    }
+
+    /// Render a `du`-style snapshot of `paths` (relative to `base`): each entry
+    /// annotated with its size — aggregated for directories — in human units
+    /// and a proportional bar, siblings sorted by descending size. This output
+    /// is a report and is not intended to round-trip through `collect_entries`.
+    fn annotate_sizes(base: &Path, paths: &[String]) -> String {
+        // Size of every file, keyed by relative path.
+        let mut file_sizes: std::collections::HashMap<String, u64> =
+            std::collections::HashMap::new();
+        for rel in paths {
+            if rel.ends_with('/') {
+                continue;
+            }
+            let len = fs::metadata(base.join(rel)).map(|m| m.len()).unwrap_or(0);
+            file_sizes.insert(rel.clone(), len);
+        }
+
+        // Bottom-up aggregation: each file's bytes flow into every ancestor dir.
+        let mut total: std::collections::HashMap<String, u64> = std::collections::HashMap::new();
+        for (rel, &len) in &file_sizes {
+            total.insert(rel.clone(), len);
+            // Every file counts toward the `./` root total, including top-level
+            // files whose only "parent" is the empty path.
+            *total.entry("./".to_string()).or_insert(0) += len;
+            let mut parent = Path::new(rel).parent();
+            while let Some(p) = parent {
+                if p.as_os_str().is_empty() {
+                    break;
+                }
+                let key = format!("{}/", p.to_string_lossy());
+                *total.entry(key).or_insert(0) += len;
+                parent = p.parent();
+            }
+        }
+        for rel in paths {
+            total.entry(rel.clone()).or_insert(0);
+        }
+
+        // Sort siblings (same parent) by descending size, then by name.
+        let parent_of = |rel: &str| -> String {
+            match Path::new(rel.trim_end_matches('/')).parent() {
+                Some(p) if !p.as_os_str().is_empty() => p.to_string_lossy().to_string(),
+                _ => String::new(),
+            }
+        };
+        let mut ordered: Vec<String> = paths.to_vec();
+        ordered.sort_by(|a, b| {
+            parent_of(a)
+                .cmp(&parent_of(b))
+                .then(total[b].cmp(&total[a]))
+                .then(a.cmp(b))
+        });
+
+        let max = total.values().copied().max().unwrap_or(0).max(1);
+        // Lead with a sentinel so a pasted `--sizes` report is recognized as a
+        // report and not re-parsed into junk directories.
+        let mut out = format!("{}\n", SIZES_MARKER);
+        for rel in &ordered {
+            let size = total[rel];
+            let filled = (size * 20 / max) as usize;
+            let bar: String = "█".repeat(filled);
+            out.push_str(&format!("{:>8}  {:<20} {}\n", human_size(size), bar, rel));
+        }
+        out.trim_end().to_string()
+    }
+
+    /// Format a byte count with binary K/M/G/T suffixes, `du -h` style.
+    fn human_size(bytes: u64) -> String {
+        const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            format!("{}{}", bytes, UNITS[unit])
+        } else {
+            format!("{:.1}{}", value, UNITS[unit])
+        }
+    }
+
+    /// Marker separating the plain tree listing from embedded content blocks.
+    const CONTENT_MARKER: &str = "=== META CONTENT ===";
+
+    /// Sentinel first line of a `--sizes` report; signals paste to treat the
+    /// payload as a human-readable report rather than a tree to recreate.
+    const SIZES_MARKER: &str = "# meta --sizes report (not a tree)";
+
+    /// Append an embedded-content section to `listing`: one block per file in
+    /// the directory, text files verbatim and binary files base64-encoded.
+    fn append_content_blocks(base: &Path, listing: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let mut out = String::from(listing);
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(CONTENT_MARKER);
+        out.push('\n');
+
+        for line in listing.lines() {
+            if line.trim().is_empty() || line.ends_with('/') || line == "." {
+                continue;
+            }
+            let file = base.join(line);
+            if !file.is_file() {
+                continue;
+            }
+            let bytes = fs::read(&file)?;
+            if is_text_file(&file) {
+                out.push_str(&format!("--- text {} {}\n", bytes.len(), line));
+                out.push_str(std::str::from_utf8(&bytes)?);
+            } else {
+                let encoded = base64::encode(&bytes);
+                out.push_str(&format!("--- base64 {} {}\n", encoded.len(), line));
+                out.push_str(&encoded);
+            }
+            out.push('\n');
+        }
+
+        Ok(out)
+    }
+
+    /// Split clipboard text into the plain tree listing and a map of embedded
+    /// file contents (decoding base64 where marked). When no content section is
+    /// present the whole text is returned as the listing and the map is empty.
+    fn split_content_blocks(s: &str) -> (String, std::collections::HashMap<String, Vec<u8>>) {
+        let mut map = std::collections::HashMap::new();
+        let needle = format!("{}\n", CONTENT_MARKER);
+        let split_at = match s.find(&needle) {
+            Some(pos) => pos,
+            None => return (s.to_string(), map),
+        };
+        let tree = s[..split_at].to_string();
+        let body = &s[split_at + needle.len()..];
+
+        let mut rest = body;
+        while let Some(nl) = rest.find('\n') {
+            let header = &rest[..nl];
+            if !header.starts_with("--- ") {
+                break;
+            }
+            let mut parts = header["--- ".len()..].splitn(3, ' ');
+            let enc = parts.next().unwrap_or("");
+            let len: usize = match parts.next().and_then(|n| n.parse().ok()) {
+                Some(n) => n,
+                None => break,
+            };
+            let path = match parts.next() {
+                Some(p) => p.to_string(),
+                None => break,
+            };
+            let payload_start = nl + 1;
+            if payload_start + len > rest.len() {
+                break;
+            }
+            let payload = &rest[payload_start..payload_start + len];
+            let decoded = match enc {
+                "base64" => match base64::decode(payload) {
+                    Ok(b) => b,
+                    Err(_) => break,
+                },
+                _ => payload.as_bytes().to_vec(),
+            };
+            map.insert(path, decoded);
+            // Advance past the payload and its trailing newline.
+            rest = &rest[payload_start + len..];
+            if let Some(stripped) = rest.strip_prefix('\n') {
+                rest = stripped;
+            }
+        }
+
+        (tree, map)
+    }
+
+    /// Auto-enable compression once the serialized payload exceeds this size.
+    const COMPRESS_THRESHOLD: usize = 64 * 1024;
+
+    /// Wrap a serialized payload for transport over the text-only clipboard.
+    /// When `compress` is set the payload is run through the self-contained
+    /// compressor and base64-wrapped so it survives the clipboard; otherwise it
+    /// is passed through verbatim for backward compatibility.
+    fn pack_payload(plain: &str, compress: bool) -> String {
+        if compress {
+            base64::encode(&compress::compress(plain.as_bytes()))
         } else {
-            Err("pbpaste failed".into())
+            plain.to_string()
+        }
+    }
+
+    /// Reverse of `pack_payload`: sniff the compression magic header and
+    /// transparently decompress, falling back to plain-text parsing when the
+    /// header is absent.
+    fn unpack_payload(text: &str) -> String {
+        if let Ok(bytes) = base64::decode(text.trim()) {
+            if bytes.starts_with(compress::MAGIC) {
+                if let Ok(plain) = compress::decompress(&bytes) {
+                    if let Ok(s) = String::from_utf8(plain) {
+                        return s;
+                    }
+                }
+            }
+        }
+        text.to_string()
+    }
+
+    /// Self-contained LZSS compressor with a length-prefixed framing.
+    ///
+    /// The stream is the `MAGIC` header, the uncompressed length as a
+    /// little-endian `u32`, then groups of up to eight tokens, each group led by
+    /// a control byte whose set bits mark literals. A 64 KiB window captures the
+    /// repetition in source trees without an external compression library;
+    /// `compress`/`decompress` are the only two entry points.
+    mod compress {
+        pub const MAGIC: &[u8] = b"MZ1\x01";
+        const MIN_MATCH: usize = 3;
+        const MAX_MATCH: usize = MIN_MATCH + 255;
+        const WINDOW: usize = 1 << 16;
+        const MAX_CHAIN: usize = 64;
+
+        pub fn compress(input: &[u8]) -> Vec<u8> {
+            let mut out = Vec::with_capacity(input.len() / 2 + MAGIC.len() + 4);
+            out.extend_from_slice(MAGIC);
+            out.extend_from_slice(&(input.len() as u32).to_le_bytes());
+
+            // Map a 3-byte prefix to recent positions for greedy matching.
+            let mut table: std::collections::HashMap<[u8; 3], Vec<usize>> =
+                std::collections::HashMap::new();
+
+            let mut tokens: Vec<Token> = Vec::new();
+            let mut i = 0usize;
+            while i < input.len() {
+                let mut best_len = 0usize;
+                let mut best_off = 0usize;
+                if i + MIN_MATCH <= input.len() {
+                    let key = [input[i], input[i + 1], input[i + 2]];
+                    if let Some(cands) = table.get(&key) {
+                        for &pos in cands.iter().rev().take(MAX_CHAIN) {
+                            if i - pos > WINDOW {
+                                break;
+                            }
+                            let mut len = 0usize;
+                            while len < MAX_MATCH
+                                && i + len < input.len()
+                                && input[pos + len] == input[i + len]
+                            {
+                                len += 1;
+                            }
+                            if len > best_len {
+                                best_len = len;
+                                best_off = i - pos;
+                            }
+                        }
+                    }
+                }
+
+                if best_len >= MIN_MATCH {
+                    tokens.push(Token::Match {
+                        off: best_off,
+                        len: best_len,
+                    });
+                    for j in i..i + best_len {
+                        if j + MIN_MATCH <= input.len() {
+                            let key = [input[j], input[j + 1], input[j + 2]];
+                            table.entry(key).or_default().push(j);
+                        }
+                    }
+                    i += best_len;
+                } else {
+                    tokens.push(Token::Literal(input[i]));
+                    if i + MIN_MATCH <= input.len() {
+                        let key = [input[i], input[i + 1], input[i + 2]];
+                        table.entry(key).or_default().push(i);
+                    }
+                    i += 1;
+                }
+            }
+
+            // Emit tokens in groups of 8 preceded by a control byte.
+            for group in tokens.chunks(8) {
+                let mut control = 0u8;
+                for (bit, tok) in group.iter().enumerate() {
+                    if let Token::Literal(_) = tok {
+                        control |= 1 << bit;
+                    }
+                }
+                out.push(control);
+                for tok in group {
+                    match *tok {
+                        Token::Literal(b) => out.push(b),
+                        Token::Match { off, len } => {
+                            let off = (off - 1) as u16;
+                            out.extend_from_slice(&off.to_le_bytes());
+                            out.push((len - MIN_MATCH) as u8);
+                        }
+                    }
+                }
+            }
+
+            out
+        }
+
+        pub fn decompress(input: &[u8]) -> Result<Vec<u8>, String> {
+            if !input.starts_with(MAGIC) {
+                return Err("bad magic".into());
+            }
+            let mut p = MAGIC.len();
+            if input.len() < p + 4 {
+                return Err("truncated header".into());
+            }
+            let expected =
+                u32::from_le_bytes([input[p], input[p + 1], input[p + 2], input[p + 3]]) as usize;
+            p += 4;
+
+            let mut out = Vec::with_capacity(expected);
+            while out.len() < expected {
+                if p >= input.len() {
+                    return Err("truncated stream".into());
+                }
+                let control = input[p];
+                p += 1;
+                for bit in 0..8 {
+                    if out.len() >= expected {
+                        break;
+                    }
+                    if control & (1 << bit) != 0 {
+                        if p >= input.len() {
+                            return Err("truncated literal".into());
+                        }
+                        out.push(input[p]);
+                        p += 1;
+                    } else {
+                        if p + 3 > input.len() {
+                            return Err("truncated match".into());
+                        }
+                        let off = u16::from_le_bytes([input[p], input[p + 1]]) as usize + 1;
+                        let len = input[p + 2] as usize + MIN_MATCH;
+                        p += 3;
+                        if off > out.len() {
+                            return Err("invalid back-reference".into());
+                        }
+                        let start = out.len() - off;
+                        for k in 0..len {
+                            let b = out[start + k];
+                            out.push(b);
+                        }
+                    }
+                }
+            }
+
+            Ok(out)
+        }
+
+        enum Token {
+            Literal(u8),
+            Match { off: usize, len: usize },
+        }
+    }
+
+    /// Minimal self-contained RFC 4648 base64 codec, so binary round-trips work
+    /// without pulling in a heavy dependency.
+    mod base64 {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        pub fn encode(input: &[u8]) -> String {
+            let mut out = String::with_capacity((input.len() + 2) / 3 * 4);
+            for chunk in input.chunks(3) {
+                let b0 = chunk[0] as u32;
+                let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+                let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+                let n = (b0 << 16) | (b1 << 8) | b2;
+                out.push(ALPHABET[((n >> 18) & 0x3f) as usize] as char);
+                out.push(ALPHABET[((n >> 12) & 0x3f) as usize] as char);
+                out.push(if chunk.len() > 1 {
+                    ALPHABET[((n >> 6) & 0x3f) as usize] as char
+                } else {
+                    '='
+                });
+                out.push(if chunk.len() > 2 {
+                    ALPHABET[(n & 0x3f) as usize] as char
+                } else {
+                    '='
+                });
+            }
+            out
+        }
+
+        pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+            fn val(c: u8) -> Result<u32, String> {
+                match c {
+                    b'A'..=b'Z' => Ok((c - b'A') as u32),
+                    b'a'..=b'z' => Ok((c - b'a' + 26) as u32),
+                    b'0'..=b'9' => Ok((c - b'0' + 52) as u32),
+                    b'+' => Ok(62),
+                    b'/' => Ok(63),
+                    _ => Err(format!("invalid base64 character: {:?}", c as char)),
+                }
+            }
+
+            let bytes: Vec<u8> = input
+                .bytes()
+                .filter(|b| !b.is_ascii_whitespace())
+                .collect();
+            let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+            for chunk in bytes.chunks(4) {
+                if chunk.len() < 2 {
+                    return Err("truncated base64 input".into());
+                }
+                let pad = chunk.iter().filter(|&&c| c == b'=').count();
+                let mut n = 0u32;
+                for (i, &c) in chunk.iter().enumerate() {
+                    let v = if c == b'=' { 0 } else { val(c)? };
+                    n |= v << (18 - 6 * i);
+                }
+                out.push((n >> 16) as u8);
+                if pad < 2 {
+                    out.push((n >> 8) as u8);
+                }
+                if pad < 1 {
+                    out.push(n as u8);
+                }
+            }
+            Ok(out)
         }
     }
 
     // ... existing code for maketree functions ...
 
-    fn run_maketree_with_entries(entries: Vec<Entry>, opts: MaketreeOptions) -> Result<(), Box<dyn std::error::Error>> {
+    fn run_maketree_with_entries(
+        entries: Vec<Entry>,
+        opts: MaketreeOptions,
+        contents: &std::collections::HashMap<String, Vec<u8>>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         // Build using a stack of path components per depth
         let mut stack: Vec<String> = Vec::new();
         for (idx, ent) in entries.iter().enumerate() {
@@ -271,6 +1179,13 @@
             } else {
                 eprintln_v(opts.verbose, 2, format!("[DEBUG] touch: {}", path.display()));
                 touch_file(&path, &opts)?;
+                // Seed real content when the clipboard carried an embedded block.
+                let rel = path.strip_prefix("./").unwrap_or(&path).to_string_lossy().to_string();
+                if let Some(bytes) = contents.get(&rel) {
+                    if !opts.dry_run {
+                        fs::write(&path, bytes)?;
+                    }
+                }
             }
 
             // Optional: if next entry is shallower or same depth, nothing to do; depth is managed above
@@ -313,24 +1228,32 @@
     }
 
     fn parse_tree_style_depth(line: &str) -> Option<(usize, String)> {
-        if let Some(conn_pos) = line.find("── ") {
-            let prefix = &line[..conn_pos];
-            let mut i = 0usize;
-            let mut depth = 0usize;
-            let bytes = prefix.as_bytes();
-            while i + 4 <= bytes.len() {
-                let chunk = &prefix[i..i + 4];
-                if chunk == "│   " || chunk == "    " {
-                    depth += 1;
-                    i += 4;
-                } else {
-                    break;
-                }
+        // Accept both the Unicode connector "── " and the ASCII "-- " emitted
+        // by `tree -A`, counting 4-char margin groups ('│', '|' or ' ' followed
+        // by spaces) before the connector.
+        let (conn_pos, conn_len) = if let Some(p) = line.find("── ") {
+            (p, "── ".len())
+        } else if let Some(p) = line.find("-- ") {
+            (p, "-- ".len())
+        } else {
+            return None;
+        };
+
+        let prefix: Vec<char> = line[..conn_pos].chars().collect();
+        let mut i = 0usize;
+        let mut depth = 0usize;
+        while i + 4 <= prefix.len() {
+            let head = prefix[i];
+            let spaces = prefix[i + 1] == ' ' && prefix[i + 2] == ' ' && prefix[i + 3] == ' ';
+            if (head == '│' || head == '|' || head == ' ') && spaces {
+                depth += 1;
+                i += 4;
+            } else {
+                break;
             }
-            let name = line[conn_pos + 3..].trim().to_string();
-            return Some((depth, name));
         }
-        None
+        let name = line[conn_pos + conn_len..].trim().to_string();
+        Some((depth, name))
     }
 
     fn parse_indent_list_depth(line: &str) -> (usize, String) {